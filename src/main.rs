@@ -1,6 +1,8 @@
+use std::fmt;
 use std::net::{IpAddr, Ipv4Addr};
+use std::str::FromStr;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 struct NetworkInfo {
     ip_address: IpAddr,
     cidr: u8,
@@ -16,17 +18,77 @@ struct NetworkInfo {
     dhcp_range_end: Option<Ipv4Addr>,
     default_gateway: Option<Ipv4Addr>,
     needs_nat: bool,
+    scope: AddressScope,
+}
+
+/// Well-known special-purpose IPv4 ranges beyond the three private blocks,
+/// per IANA's special-purpose address registry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct AddressScope {
+    /// 127.0.0.0/8
+    loopback: bool,
+    /// 169.254.0.0/16
+    link_local: bool,
+    /// 100.64.0.0/10, used for carrier-grade NAT (shared address space).
+    shared_space: bool,
+    /// 0.0.0.0/8, "this host on this network".
+    this_host: bool,
+    /// 198.18.0.0/15, reserved for network device benchmarking.
+    benchmarking: bool,
+    /// 192.0.2.0/24, 198.51.100.0/24, 203.0.113.0/24 (TEST-NET-1/2/3).
+    documentation: bool,
+    /// 192.88.99.0/24, the former 6to4 relay anycast block.
+    relay_6to4: bool,
+}
+
+impl AddressScope {
+    fn classify(octets: [u8; 4]) -> Self {
+        AddressScope {
+            loopback: octets[0] == 127,
+            link_local: octets[0] == 169 && octets[1] == 254,
+            shared_space: octets[0] == 100 && (64..128).contains(&octets[1]),
+            this_host: octets[0] == 0,
+            benchmarking: octets[0] == 198 && (octets[1] == 18 || octets[1] == 19),
+            documentation: matches!(
+                (octets[0], octets[1], octets[2]),
+                (192, 0, 2) | (198, 51, 100) | (203, 0, 113)
+            ),
+            relay_6to4: octets[0] == 192 && octets[1] == 88 && octets[2] == 99,
+        }
+    }
+
+    /// True if none of the special-purpose categories above apply.
+    fn is_unrestricted(&self) -> bool {
+        !(self.loopback
+            || self.link_local
+            || self.shared_space
+            || self.this_host
+            || self.benchmarking
+            || self.documentation
+            || self.relay_6to4)
+    }
+}
+
+/// Computes the subnet mask for a CIDR prefix (0..=32) as a `u32`, without
+/// overflowing the shift when `cidr` is 0.
+fn cidr_mask(cidr: u8) -> u32 {
+    if cidr == 0 {
+        0
+    } else {
+        u32::MAX << (32 - cidr)
+    }
 }
 
 impl NetworkInfo {
-    pub fn analyze_network(ip_address: IpAddr, cidr: u8) -> Self {
-        let octets: [u8; 4];
-        let ipv4_addr = if let IpAddr::V4(ipv4) = ip_address {
-            octets = ipv4.octets();
-            ipv4
-        } else {
-            panic!("Only IPv4 addresses are supported in this implementation.");
+    pub fn analyze_network(ip_address: IpAddr, cidr: u8) -> Result<Self, NetworkError> {
+        let ipv4_addr = match ip_address {
+            IpAddr::V4(ipv4) => ipv4,
+            IpAddr::V6(_) => return Err(NetworkError::UnsupportedAddressFamily),
         };
+        if cidr > 32 {
+            return Err(NetworkError::PrefixTooLong);
+        }
+        let octets = ipv4_addr.octets();
         let ip_as_u32 = u32::from(ipv4_addr);
 
         let is_private = match (octets[0], octets[1]) {
@@ -44,19 +106,18 @@ impl NetworkInfo {
             _ => 'E',
         };
 
-        let subnet_mask = Ipv4Addr::from(u32::MAX << (32 - cidr));
+        let mask = cidr_mask(cidr);
+        let subnet_mask = Ipv4Addr::from(mask);
 
         let broadcast_address = match ip_class{
                 'D' | 'E' => None,
             _ => {
-                let mask = u32::MAX << (32 - cidr);
                 let broadcast_as_u32 = ip_as_u32 | !mask;
                 Some(Ipv4Addr::from(broadcast_as_u32))
             }
         };
 
         let network_address = {
-            let mask = u32::MAX << (32 - cidr);
             let network_as_u32 = ip_as_u32 & mask;
             Ipv4Addr::from(network_as_u32)
         };
@@ -87,7 +148,7 @@ impl NetworkInfo {
 
         let usable_hosts = match ip_class {
             'E' | 'D' => 0,
-            _ => if cidr > 30 { 0 } else { 2_u32.pow(32_u32 - u32::from(cidr)) - 2 }
+            _ => if cidr > 30 { 0 } else { (2_u64.pow(32_u32 - u32::from(cidr)) - 2) as u32 }
         };
 
         let dhcp_range_start = match cidr {
@@ -122,9 +183,10 @@ impl NetworkInfo {
             }
         };
 
-        let needs_nat = is_private;
+        let scope = AddressScope::classify(octets);
+        let needs_nat = is_private || !scope.is_unrestricted();
 
-        NetworkInfo {
+        Ok(NetworkInfo {
             ip_address,
             cidr,
             subnet_mask,
@@ -139,7 +201,495 @@ impl NetworkInfo {
             dhcp_range_end,
             default_gateway,
             needs_nat,
-        }   
+            scope,
+        })
+    }
+
+    /// Like [`NetworkInfo::analyze_network`], but additionally requires that
+    /// `ip_address` is already a canonical network address, i.e. has no host
+    /// bits set for the given prefix.
+    pub fn analyze_canonical_network(ip_address: IpAddr, cidr: u8) -> Result<Self, NetworkError> {
+        let info = Self::analyze_network(ip_address, cidr)?;
+        if info.ip_address != IpAddr::V4(info.network_address) {
+            return Err(NetworkError::HostBitsSet);
+        }
+        Ok(info)
+    }
+
+    /// Like [`NetworkInfo::analyze_network`], but derives `default_gateway`
+    /// and the `dhcp_range_*` fields from `policy` instead of the built-in
+    /// offsets, clamped so they never exceed `host_range_end` or collide with
+    /// the gateway. Networks with no usable host range (multicast/reserved
+    /// classes, or /31 and /32) are unaffected by `policy`.
+    pub fn analyze_network_with_policy(
+        ip_address: IpAddr,
+        cidr: u8,
+        policy: &AllocationPolicy,
+    ) -> Result<Self, NetworkError> {
+        let mut info = Self::analyze_network(ip_address, cidr)?;
+        if let (Some(host_range_start), Some(host_range_end)) =
+            (info.host_range_start, info.host_range_end)
+        {
+            let (gateway, dhcp_start, dhcp_end) =
+                allocate_gateway_and_pool(host_range_start, host_range_end, policy);
+            info.default_gateway = Some(gateway);
+            info.dhcp_range_start = Some(dhcp_start);
+            info.dhcp_range_end = Some(dhcp_end);
+        }
+        Ok(info)
+    }
+
+    /// Splits this network into all equally-sized child networks at `new_prefix`.
+    ///
+    /// `new_prefix` must be between this network's own prefix and 32
+    /// inclusive; a `new_prefix` equal to `self.cidr` yields a single-element
+    /// `Vec` containing an equivalent network. A `new_prefix` more than
+    /// [`MAX_SUBNET_SPLIT_BITS`] longer than `self.cidr` is rejected with
+    /// [`NetworkError::TooManySubnets`].
+    pub fn subnets(&self, new_prefix: u8) -> Result<Vec<NetworkInfo>, NetworkError> {
+        if new_prefix > 32 {
+            return Err(NetworkError::PrefixTooLong);
+        }
+        if new_prefix < self.cidr {
+            return Err(NetworkError::NewPrefixTooShort);
+        }
+
+        let split_bits = u32::from(new_prefix - self.cidr);
+        if split_bits > MAX_SUBNET_SPLIT_BITS {
+            return Err(NetworkError::TooManySubnets);
+        }
+
+        let count: u64 = 1u64 << split_bits;
+        let step: u64 = 1u64 << (32 - u32::from(new_prefix));
+        let base: u64 = u64::from(u32::from(self.network_address));
+
+        let mut children = Vec::with_capacity(count as usize);
+        for i in 0..count {
+            let child_address = Ipv4Addr::from((base + i * step) as u32);
+            children.push(NetworkInfo::analyze_network(IpAddr::V4(child_address), new_prefix)?);
+        }
+        Ok(children)
+    }
+
+    /// Iterates over every address in the network, including the network and
+    /// broadcast addresses.
+    pub fn addresses(&self) -> impl Iterator<Item = Ipv4Addr> {
+        let start = u32::from(self.network_address);
+        let end = start | !cidr_mask(self.cidr);
+        (start..=end).map(Ipv4Addr::from)
+    }
+
+    /// Iterates over the usable host addresses in the network: network+1
+    /// through broadcast-1 for prefixes of /30 or shorter, both endpoints for
+    /// a /31 point-to-point link (RFC 3021), and the single address for /32.
+    /// Yields nothing for class D (multicast) and class E (reserved) addresses.
+    pub fn hosts(&self) -> impl Iterator<Item = Ipv4Addr> {
+        let (start, end) = match self.ip_class {
+            'D' | 'E' => (1, 0),
+            _ => match self.cidr {
+                32 => {
+                    let address = u32::from(self.network_address);
+                    (address, address)
+                }
+                31 => (
+                    u32::from(self.network_address),
+                    u32::from(self.broadcast_address.unwrap_or(self.network_address)),
+                ),
+                _ => match self.broadcast_address {
+                    Some(broadcast) => (
+                        u32::from(self.network_address) + 1,
+                        u32::from(broadcast).saturating_sub(1),
+                    ),
+                    None => (1, 0),
+                },
+            },
+        };
+        (start..=end).map(Ipv4Addr::from)
+    }
+
+    /// True for 127.0.0.0/8, the loopback range.
+    pub fn is_loopback(&self) -> bool {
+        self.scope.loopback
+    }
+
+    /// True for 169.254.0.0/16, the link-local ("APIPA") range.
+    pub fn is_link_local(&self) -> bool {
+        self.scope.link_local
+    }
+
+    /// True for 100.64.0.0/10, shared address space used for carrier-grade NAT.
+    pub fn is_shared_space(&self) -> bool {
+        self.scope.shared_space
+    }
+
+    /// True for 0.0.0.0/8, "this host on this network".
+    pub fn is_this_host(&self) -> bool {
+        self.scope.this_host
+    }
+
+    /// True for 198.18.0.0/15, reserved for network device benchmarking.
+    pub fn is_benchmarking(&self) -> bool {
+        self.scope.benchmarking
+    }
+
+    /// True for the TEST-NET-1/2/3 documentation ranges (192.0.2.0/24,
+    /// 198.51.100.0/24, 203.0.113.0/24).
+    pub fn is_documentation(&self) -> bool {
+        self.scope.documentation
+    }
+
+    /// True for 192.88.99.0/24, the former 6to4 relay anycast block.
+    pub fn is_6to4_relay(&self) -> bool {
+        self.scope.relay_6to4
+    }
+
+    /// True only when the address is in none of the private, multicast,
+    /// reserved, or special-purpose ranges above, i.e. it is globally
+    /// routable on the public internet.
+    pub fn is_global(&self) -> bool {
+        !self.is_private
+            && self.ip_class != 'D'
+            && self.ip_class != 'E'
+            && self.scope.is_unrestricted()
+    }
+
+    /// Returns the immediate parent network, i.e. this network's prefix minus
+    /// one bit, or `None` at /0 (there is no shorter prefix).
+    pub fn supernet(&self) -> Option<NetworkInfo> {
+        if self.cidr == 0 {
+            return None;
+        }
+        let new_prefix = self.cidr - 1;
+        let mask = cidr_mask(new_prefix);
+        let network_address = Ipv4Addr::from(u32::from(self.network_address) & mask);
+        Some(
+            NetworkInfo::analyze_network(IpAddr::V4(network_address), new_prefix)
+                .expect("the supernet of a valid network is itself a valid network"),
+        )
+    }
+}
+
+/// Upper bound on how many bits [`NetworkInfo::subnets`] will split, i.e. at
+/// most `2^16` child networks per call.
+const MAX_SUBNET_SPLIT_BITS: u32 = 16;
+
+/// Returns `true` if `parent` fully covers `child`'s address range.
+fn contains_network(parent: &NetworkInfo, child: &NetworkInfo) -> bool {
+    if parent.cidr > child.cidr {
+        return false;
+    }
+    let mask = cidr_mask(parent.cidr);
+    (u32::from(child.network_address) & mask) == u32::from(parent.network_address)
+}
+
+/// Returns `true` if `a` and `b` are equal-prefix "buddy" networks that merge
+/// cleanly into a single network one bit shorter: their network addresses
+/// differ only in bit `32 - prefix`, and `a` (the lower one) is aligned to a
+/// `2^(33-prefix)` boundary.
+fn are_buddies(a: &NetworkInfo, b: &NetworkInfo) -> bool {
+    if a.cidr != b.cidr || a.cidr == 0 {
+        return false;
+    }
+    let block: u64 = 1u64 << (32 - u32::from(a.cidr));
+    let parent_block = block * 2;
+    let a_addr = u64::from(u32::from(a.network_address));
+    let b_addr = u64::from(u32::from(b.network_address));
+    a_addr % parent_block == 0 && b_addr == a_addr + block
+}
+
+/// Merges adjacent and contained networks into the smallest set of CIDR
+/// blocks that cover the same address space.
+fn aggregate(nets: &[NetworkInfo]) -> Vec<NetworkInfo> {
+    let mut current: Vec<NetworkInfo> = nets.to_vec();
+
+    loop {
+        current.sort_by_key(|n| (u32::from(n.network_address), n.cidr));
+
+        let mut deduped: Vec<NetworkInfo> = Vec::with_capacity(current.len());
+        for net in current {
+            let contained = deduped
+                .last()
+                .is_some_and(|prev| contains_network(prev, &net));
+            if !contained {
+                deduped.push(net);
+            }
+        }
+
+        let mut merged: Vec<NetworkInfo> = Vec::with_capacity(deduped.len());
+        let mut changed = false;
+        let mut i = 0;
+        while i < deduped.len() {
+            if i + 1 < deduped.len() && are_buddies(&deduped[i], &deduped[i + 1]) {
+                merged.push(
+                    deduped[i]
+                        .supernet()
+                        .expect("buddies always have cidr >= 1 and thus a supernet"),
+                );
+                changed = true;
+                i += 2;
+            } else {
+                merged.push(deduped[i]);
+                i += 1;
+            }
+        }
+
+        current = merged;
+        if !changed {
+            return current;
+        }
+    }
+}
+
+/// Where to place the default gateway within a network's usable-host range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GatewayPosition {
+    /// The first usable host address.
+    First,
+    /// The last usable host address.
+    Last,
+}
+
+/// Configures how [`NetworkInfo::analyze_network_with_policy`] places the
+/// default gateway and DHCP pool within a network's usable-host range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct AllocationPolicy {
+    /// Where the default gateway sits in the usable-host range.
+    gateway_position: GatewayPosition,
+    /// How many usable hosts to skip, from the start of the range, before the
+    /// DHCP pool begins.
+    dhcp_pool_start_offset: u32,
+    /// How many addresses the DHCP pool spans, before clamping to the
+    /// usable-host range. A size of 0 is treated as 1.
+    dhcp_pool_size: u32,
+}
+
+impl Default for AllocationPolicy {
+    /// Gateway on the first usable host, DHCP pool starting 9 hosts in and
+    /// spanning 91 addresses. For prefixes of /24 and shorter this gives the
+    /// same values as [`NetworkInfo::analyze_network`]; for /25 and longer,
+    /// `analyze_network` starts the pool at the first usable host, while this
+    /// policy still skips 9 hosts (clamped to the usable-host range).
+    fn default() -> Self {
+        AllocationPolicy {
+            gateway_position: GatewayPosition::First,
+            dhcp_pool_start_offset: 9,
+            dhcp_pool_size: 91,
+        }
+    }
+}
+
+/// Computes `(gateway, dhcp_start, dhcp_end)` from a usable-host range and an
+/// [`AllocationPolicy`], clamping the DHCP pool to the host range and nudging
+/// it off the gateway address if the two would otherwise collide.
+fn allocate_gateway_and_pool(
+    host_range_start: Ipv4Addr,
+    host_range_end: Ipv4Addr,
+    policy: &AllocationPolicy,
+) -> (Ipv4Addr, Ipv4Addr, Ipv4Addr) {
+    let start = u32::from(host_range_start);
+    let end = u32::from(host_range_end);
+
+    let gateway = match policy.gateway_position {
+        GatewayPosition::First => start,
+        GatewayPosition::Last => end,
+    };
+
+    let mut dhcp_start = start.saturating_add(policy.dhcp_pool_start_offset).min(end);
+    let mut dhcp_end = dhcp_start
+        .saturating_add(policy.dhcp_pool_size.saturating_sub(1))
+        .min(end);
+
+    // Nudge the pool off the gateway address. Which direction has room
+    // depends on where the gateway sits: with the gateway at the start of
+    // the range there's nowhere to go but up, and vice versa for the end -
+    // pushing "forward" when already pinned at `end` just re-collides.
+    match policy.gateway_position {
+        GatewayPosition::First => {
+            if dhcp_start == gateway {
+                dhcp_start = dhcp_start.saturating_add(1).min(end);
+                dhcp_end = dhcp_end.max(dhcp_start);
+            }
+            if dhcp_end == gateway && dhcp_end > dhcp_start {
+                dhcp_end = dhcp_end.saturating_sub(1).max(dhcp_start);
+            }
+        }
+        GatewayPosition::Last => {
+            if dhcp_end == gateway {
+                dhcp_end = dhcp_end.saturating_sub(1).max(start);
+                dhcp_start = dhcp_start.min(dhcp_end);
+            }
+            if dhcp_start == gateway && dhcp_start < dhcp_end {
+                dhcp_start = dhcp_start.saturating_add(1).min(dhcp_end);
+            }
+        }
+    }
+
+    (Ipv4Addr::from(gateway), Ipv4Addr::from(dhcp_start), Ipv4Addr::from(dhcp_end))
+}
+
+/// Errors produced while constructing a [`NetworkInfo`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NetworkError {
+    /// The given address was not an IPv4 address.
+    UnsupportedAddressFamily,
+    /// The CIDR prefix was greater than 32.
+    PrefixTooLong,
+    /// The address had host bits set where a canonical network address was required.
+    HostBitsSet,
+    /// [`NetworkInfo::subnets`] was asked for a prefix shorter than the network's own.
+    NewPrefixTooShort,
+    /// [`NetworkInfo::subnets`] would produce more child networks than it is willing to materialize.
+    TooManySubnets,
+}
+
+impl fmt::Display for NetworkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            NetworkError::UnsupportedAddressFamily => "only IPv4 addresses are supported",
+            NetworkError::PrefixTooLong => "CIDR prefix must be between 0 and 32",
+            NetworkError::HostBitsSet => "address has host bits set",
+            NetworkError::NewPrefixTooShort => "new prefix must be at least as long as the current one",
+            NetworkError::TooManySubnets => "splitting into that many subnets is not supported",
+        };
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for NetworkError {}
+
+/// Errors produced while parsing a `"<ip>/<prefix>"` CIDR string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CidrParseError {
+    /// The string did not contain a `/` separating address and prefix.
+    MissingPrefix,
+    /// The prefix half did not parse as a `u8`: it was empty, had a non-digit,
+    /// or was greater than 255.
+    InvalidPrefix,
+    /// The prefix parsed as a `u8` but is greater than 32.
+    PrefixOutOfRange,
+    /// The address half did not split into exactly four octets.
+    InvalidOctetCount,
+    /// One of the four octets was empty (e.g. "255.0..1").
+    EmptyOctet,
+    /// An octet had a leading zero, e.g. "01" or "00" (only a bare "0" is valid).
+    LeadingZero,
+    /// An octet contained a non-digit character.
+    InvalidOctet,
+    /// An octet parsed as a number but is greater than 255.
+    OctetOutOfRange,
+}
+
+impl fmt::Display for CidrParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            CidrParseError::MissingPrefix => "missing \"/<prefix>\" suffix",
+            CidrParseError::InvalidPrefix => "prefix is not a valid integer",
+            CidrParseError::PrefixOutOfRange => "prefix must be between 0 and 32",
+            CidrParseError::InvalidOctetCount => "address must have exactly four octets",
+            CidrParseError::EmptyOctet => "address contains an empty octet",
+            CidrParseError::LeadingZero => "octet has a leading zero",
+            CidrParseError::InvalidOctet => "octet contains a non-digit character",
+            CidrParseError::OctetOutOfRange => "octet is greater than 255",
+        };
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for CidrParseError {}
+
+/// Parses a strict dotted-quad octet, rejecting empty, non-digit, leading-zero
+/// (octal-looking) and out-of-range forms.
+fn parse_strict_octet(octet: &str) -> Result<u8, CidrParseError> {
+    if octet.is_empty() {
+        return Err(CidrParseError::EmptyOctet);
+    }
+    if octet.len() > 1 && octet.starts_with('0') {
+        return Err(CidrParseError::LeadingZero);
+    }
+    if !octet.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(CidrParseError::InvalidOctet);
+    }
+    octet
+        .parse::<u16>()
+        .ok()
+        .and_then(|value| u8::try_from(value).ok())
+        .ok_or(CidrParseError::OctetOutOfRange)
+}
+
+/// Parses a strict `"a.b.c.d"` address: exactly four octets, each validated by
+/// [`parse_strict_octet`].
+fn parse_strict_ipv4(address: &str) -> Result<Ipv4Addr, CidrParseError> {
+    let parts: Vec<&str> = address.split('.').collect();
+    if parts.len() != 4 {
+        return Err(CidrParseError::InvalidOctetCount);
+    }
+
+    let mut octets = [0u8; 4];
+    for (slot, part) in octets.iter_mut().zip(parts.iter()) {
+        *slot = parse_strict_octet(part)?;
+    }
+    Ok(Ipv4Addr::from(octets))
+}
+
+/// Parses a `"<ip>/<prefix>"` CIDR string into its address and prefix length,
+/// e.g. `"192.168.1.0/24"`. The address is parsed strictly: see
+/// [`parse_strict_ipv4`] for the octet rules.
+fn parse_cidr(input: &str) -> Result<(Ipv4Addr, u8), CidrParseError> {
+    let mut halves = input.splitn(2, '/');
+    let address_part = halves.next().ok_or(CidrParseError::MissingPrefix)?;
+    let prefix_part = halves.next().ok_or(CidrParseError::MissingPrefix)?;
+
+    let prefix: u8 = prefix_part
+        .parse()
+        .map_err(|_| CidrParseError::InvalidPrefix)?;
+    if prefix > 32 {
+        return Err(CidrParseError::PrefixOutOfRange);
+    }
+
+    let address = parse_strict_ipv4(address_part)?;
+    Ok((address, prefix))
+}
+
+/// Errors produced by [`NetworkInfo::from_str`], covering both the string
+/// syntax and the resulting network's validity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ParseNetworkError {
+    Format(CidrParseError),
+    Network(NetworkError),
+}
+
+impl fmt::Display for ParseNetworkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseNetworkError::Format(err) => write!(f, "{err}"),
+            ParseNetworkError::Network(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseNetworkError {}
+
+impl From<CidrParseError> for ParseNetworkError {
+    fn from(err: CidrParseError) -> Self {
+        ParseNetworkError::Format(err)
+    }
+}
+
+impl From<NetworkError> for ParseNetworkError {
+    fn from(err: NetworkError) -> Self {
+        ParseNetworkError::Network(err)
+    }
+}
+
+impl FromStr for NetworkInfo {
+    type Err = ParseNetworkError;
+
+    /// Parses CIDR notation such as `"192.168.1.0/24"` and analyzes the
+    /// resulting network.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (address, prefix) = parse_cidr(s)?;
+        let info = NetworkInfo::analyze_network(IpAddr::V4(address), prefix)?;
+        Ok(info)
     }
 }
 
@@ -150,7 +700,7 @@ mod tests {
 
     #[test]
     fn test_class_c_network() {
-        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(192, 168, 1, 0)), 24);
+        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(192, 168, 1, 0)), 24).unwrap();
         assert_eq!(network_info.subnet_mask, Ipv4Addr::new(255, 255, 255, 0));
         assert_eq!(network_info.ip_class, 'C');
         assert!(network_info.is_private);
@@ -166,7 +716,7 @@ mod tests {
 
     #[test]
     fn test_class_a_network() {
-        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 0)), 8);
+        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 0)), 8).unwrap();
         assert_eq!(network_info.subnet_mask, Ipv4Addr::new(255, 0, 0, 0));
         assert_eq!(network_info.ip_class, 'A');
         assert!(network_info.is_private);
@@ -182,7 +732,7 @@ mod tests {
 
     #[test]
     fn test_public_ip_class_b() {
-        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(172, 32, 0, 0)), 16);
+        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(172, 32, 0, 0)), 16).unwrap();
         assert_eq!(network_info.subnet_mask, Ipv4Addr::new(255, 255, 0, 0));
         assert_eq!(network_info.ip_class, 'B');
         assert!(!network_info.is_private);
@@ -198,7 +748,7 @@ mod tests {
 
     #[test]
     fn test_smallest_subnet_class_c() {
-        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(192, 168, 1, 0)), 30);
+        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(192, 168, 1, 0)), 30).unwrap();
         assert_eq!(network_info.subnet_mask, Ipv4Addr::new(255, 255, 255, 252));
         assert_eq!(network_info.ip_class, 'C');
         assert!(network_info.is_private);
@@ -214,7 +764,7 @@ mod tests {
 
     #[test]
     fn test_single_host_subnet() {
-        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)), 32);
+        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)), 32).unwrap();
         assert_eq!(network_info.subnet_mask, Ipv4Addr::new(255, 255, 255, 255));
         assert_eq!(network_info.ip_class, 'A');
         assert!(network_info.is_private);
@@ -230,7 +780,7 @@ mod tests {
 
     #[test]
     fn test_large_class_b_subnet() {
-        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(172, 16, 0, 0)), 12);
+        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(172, 16, 0, 0)), 12).unwrap();
         assert_eq!(network_info.subnet_mask, Ipv4Addr::new(255, 240, 0, 0));
         assert_eq!(network_info.ip_class, 'B');
         assert!(network_info.is_private);
@@ -246,7 +796,7 @@ mod tests {
 
     #[test]
     fn test_class_d_multicast() {
-        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(224, 0, 0, 1)), 4);
+        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(224, 0, 0, 1)), 4).unwrap();
         assert_eq!(network_info.subnet_mask, Ipv4Addr::new(240, 0, 0, 0));
         assert_eq!(network_info.ip_class, 'D');
         assert!(!network_info.is_private);
@@ -263,7 +813,7 @@ mod tests {
 
     #[test]
     fn test_class_e_experimental() {
-        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(240, 0, 0, 1)), 4);
+        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(240, 0, 0, 1)), 4).unwrap();
         assert_eq!(network_info.subnet_mask, Ipv4Addr::new(240, 0, 0, 0));
         assert_eq!(network_info.ip_class, 'E');
         assert!(!network_info.is_private);
@@ -280,7 +830,7 @@ mod tests {
 
     #[test]
     fn test_public_class_b_ip() {
-        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(130, 10, 0, 0)), 16);
+        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(130, 10, 0, 0)), 16).unwrap();
         assert_eq!(network_info.subnet_mask, Ipv4Addr::new(255, 255, 0, 0));
         assert_eq!(network_info.ip_class, 'B');
         assert!(!network_info.is_private);
@@ -296,7 +846,7 @@ mod tests {
     #[test]
     fn test_cidr_31() {
         // Test a /31 subnet, commonly used for point-to-point links
-        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(192, 168, 1, 0)), 31);
+        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(192, 168, 1, 0)), 31).unwrap();
         assert_eq!(network_info.subnet_mask, Ipv4Addr::new(255, 255, 255, 254));
         assert_eq!(network_info.usable_hosts, 0);
         assert_eq!(network_info.broadcast_address, Some(Ipv4Addr::new(192, 168, 1, 1)));
@@ -309,7 +859,7 @@ mod tests {
     #[test]
     fn test_cidr_32() {
         // Test a /32 subnet, representing a single IP with no host range
-        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(10, 1, 1, 1)), 32);
+        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(10, 1, 1, 1)), 32).unwrap();
         assert_eq!(network_info.subnet_mask, Ipv4Addr::new(255, 255, 255, 255));
         assert_eq!(network_info.usable_hosts, 0);
         assert_eq!(network_info.broadcast_address, Some(Ipv4Addr::new(10, 1, 1, 1)));
@@ -322,12 +872,12 @@ mod tests {
     #[test]
     fn test_varied_cidr_class_a() {
         // Test Class A with different CIDR values
-        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(10, 10, 10, 10)), 8);
+        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(10, 10, 10, 10)), 8).unwrap();
         assert_eq!(network_info.subnet_mask, Ipv4Addr::new(255, 0, 0, 0));
         assert_eq!(network_info.usable_hosts, 16777214);
         assert_eq!(network_info.broadcast_address, Some(Ipv4Addr::new(10, 255, 255, 255)));
 
-        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(10, 10, 10, 10)), 16);
+        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(10, 10, 10, 10)), 16).unwrap();
         assert_eq!(network_info.subnet_mask, Ipv4Addr::new(255, 255, 0, 0));
         assert_eq!(network_info.usable_hosts, 65534);
         assert_eq!(network_info.broadcast_address, Some(Ipv4Addr::new(10, 10, 255, 255)));
@@ -336,12 +886,12 @@ mod tests {
     #[test]
     fn test_varied_cidr_class_b() {
         // Test Class B with CIDR values less than and greater than the default /16
-        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(172, 16, 10, 10)), 12);
+        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(172, 16, 10, 10)), 12).unwrap();
         assert_eq!(network_info.subnet_mask, Ipv4Addr::new(255, 240, 0, 0));
         assert_eq!(network_info.usable_hosts, 1048574);
         assert_eq!(network_info.broadcast_address, Some(Ipv4Addr::new(172, 31, 255, 255)));
 
-        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(172, 16, 10, 10)), 24);
+        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(172, 16, 10, 10)), 24).unwrap();
         assert_eq!(network_info.subnet_mask, Ipv4Addr::new(255, 255, 255, 0));
         assert_eq!(network_info.usable_hosts, 254);
         assert_eq!(network_info.broadcast_address, Some(Ipv4Addr::new(172, 16, 10, 255)));
@@ -350,12 +900,12 @@ mod tests {
     #[test]
     fn test_varied_cidr_class_c() {
         // Test Class C with CIDR values less than and greater than the default /24
-        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(192, 168, 10, 10)), 20);
+        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(192, 168, 10, 10)), 20).unwrap();
         assert_eq!(network_info.subnet_mask, Ipv4Addr::new(255, 255, 240, 0));
         assert_eq!(network_info.usable_hosts, 4094);
         assert_eq!(network_info.broadcast_address, Some(Ipv4Addr::new(192, 168, 15, 255)));
 
-        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(192, 168, 10, 10)), 28);
+        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(192, 168, 10, 10)), 28).unwrap();
         assert_eq!(network_info.subnet_mask, Ipv4Addr::new(255, 255, 255, 240));
         assert_eq!(network_info.usable_hosts, 14);
         assert_eq!(network_info.broadcast_address, Some(Ipv4Addr::new(192, 168, 10, 15)));
@@ -364,11 +914,401 @@ mod tests {
     #[test]
     fn test_non_boundary_address() {
         // Test a Class A IP with /16 that doesn't align on a /16 boundary
-        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(10, 10, 10, 10)), 16);
+        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(10, 10, 10, 10)), 16).unwrap();
         assert_eq!(network_info.network_address, Ipv4Addr::new(10, 10, 0, 0));
         assert_eq!(network_info.broadcast_address, Some(Ipv4Addr::new(10, 10, 255, 255)));
         assert_eq!(network_info.host_range_start, Some(Ipv4Addr::new(10, 10, 0, 1)));
         assert_eq!(network_info.host_range_end, Some(Ipv4Addr::new(10, 10, 255, 254)));
         assert_eq!(network_info.usable_hosts, 65534);
     }
+
+    #[test]
+    fn test_parse_cidr_valid() {
+        let network_info: NetworkInfo = "192.168.1.0/24".parse().unwrap();
+        assert_eq!(network_info.network_address, Ipv4Addr::new(192, 168, 1, 0));
+        assert_eq!(network_info.cidr, 24);
+        assert_eq!(network_info.subnet_mask, Ipv4Addr::new(255, 255, 255, 0));
+    }
+
+    #[test]
+    fn test_parse_cidr_missing_prefix() {
+        assert_eq!("192.168.1.0".parse::<NetworkInfo>(), Err(ParseNetworkError::Format(CidrParseError::MissingPrefix)));
+    }
+
+    #[test]
+    fn test_parse_cidr_invalid_prefix() {
+        assert_eq!("192.168.1.0/abc".parse::<NetworkInfo>(), Err(ParseNetworkError::Format(CidrParseError::InvalidPrefix)));
+    }
+
+    #[test]
+    fn test_parse_cidr_prefix_out_of_range() {
+        assert_eq!("192.168.1.0/33".parse::<NetworkInfo>(), Err(ParseNetworkError::Format(CidrParseError::PrefixOutOfRange)));
+    }
+
+    #[test]
+    fn test_parse_cidr_wrong_octet_count() {
+        assert_eq!("192.168.1/24".parse::<NetworkInfo>(), Err(ParseNetworkError::Format(CidrParseError::InvalidOctetCount)));
+        assert_eq!("192.168.1.0.1/24".parse::<NetworkInfo>(), Err(ParseNetworkError::Format(CidrParseError::InvalidOctetCount)));
+    }
+
+    #[test]
+    fn test_parse_cidr_empty_octet() {
+        assert_eq!("255.0..1/24".parse::<NetworkInfo>(), Err(ParseNetworkError::Format(CidrParseError::EmptyOctet)));
+    }
+
+    #[test]
+    fn test_parse_cidr_leading_zero() {
+        assert_eq!("255.0.0.01/24".parse::<NetworkInfo>(), Err(ParseNetworkError::Format(CidrParseError::LeadingZero)));
+        assert_eq!("255.0.0.00/24".parse::<NetworkInfo>(), Err(ParseNetworkError::Format(CidrParseError::LeadingZero)));
+        assert!("255.0.0.0/24".parse::<NetworkInfo>().is_ok());
+    }
+
+    #[test]
+    fn test_parse_cidr_octet_out_of_range() {
+        assert_eq!("255.0.0.256/24".parse::<NetworkInfo>(), Err(ParseNetworkError::Format(CidrParseError::OctetOutOfRange)));
+    }
+
+    #[test]
+    fn test_analyze_network_rejects_ipv6() {
+        let result = NetworkInfo::analyze_network(IpAddr::V6(std::net::Ipv6Addr::LOCALHOST), 24);
+        assert_eq!(result, Err(NetworkError::UnsupportedAddressFamily));
+    }
+
+    #[test]
+    fn test_analyze_network_rejects_prefix_too_long() {
+        let result = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(192, 168, 1, 0)), 33);
+        assert_eq!(result, Err(NetworkError::PrefixTooLong));
+    }
+
+    #[test]
+    fn test_analyze_network_cidr_zero_does_not_panic() {
+        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 0)), 0).unwrap();
+        assert_eq!(network_info.network_address, Ipv4Addr::new(0, 0, 0, 0));
+        assert_eq!(network_info.usable_hosts, 4_294_967_294);
+
+        let network_info: NetworkInfo = "10.0.0.0/0".parse().unwrap();
+        assert_eq!(network_info.usable_hosts, 4_294_967_294);
+    }
+
+    #[test]
+    fn test_analyze_canonical_network_accepts_network_address() {
+        let result = NetworkInfo::analyze_canonical_network(IpAddr::V4(Ipv4Addr::new(192, 168, 1, 0)), 24);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_analyze_canonical_network_rejects_host_bits() {
+        let result = NetworkInfo::analyze_canonical_network(IpAddr::V4(Ipv4Addr::new(192, 168, 1, 10)), 24);
+        assert_eq!(result, Err(NetworkError::HostBitsSet));
+    }
+
+    #[test]
+    fn test_subnets_splits_into_equal_blocks() {
+        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(192, 168, 1, 0)), 24).unwrap();
+        let children = network_info.subnets(26).unwrap();
+        let network_addresses: Vec<Ipv4Addr> = children.iter().map(|c| c.network_address).collect();
+        assert_eq!(
+            network_addresses,
+            vec![
+                Ipv4Addr::new(192, 168, 1, 0),
+                Ipv4Addr::new(192, 168, 1, 64),
+                Ipv4Addr::new(192, 168, 1, 128),
+                Ipv4Addr::new(192, 168, 1, 192),
+            ]
+        );
+        assert!(children.iter().all(|c| c.cidr == 26));
+    }
+
+    #[test]
+    fn test_subnets_same_prefix_is_single_network() {
+        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 0)), 8).unwrap();
+        let children = network_info.subnets(8).unwrap();
+        assert_eq!(children.len(), 1);
+        assert_eq!(children[0].network_address, Ipv4Addr::new(10, 0, 0, 0));
+    }
+
+    #[test]
+    fn test_subnets_rejects_shorter_prefix() {
+        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(192, 168, 1, 0)), 24).unwrap();
+        assert_eq!(network_info.subnets(16), Err(NetworkError::NewPrefixTooShort));
+    }
+
+    #[test]
+    fn test_subnets_rejects_prefix_too_long() {
+        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(192, 168, 1, 0)), 24).unwrap();
+        assert_eq!(network_info.subnets(33), Err(NetworkError::PrefixTooLong));
+    }
+
+    #[test]
+    fn test_subnets_caps_huge_splits() {
+        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 0)), 8).unwrap();
+        assert_eq!(network_info.subnets(32), Err(NetworkError::TooManySubnets));
+    }
+
+    #[test]
+    fn test_addresses_includes_network_and_broadcast() {
+        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(192, 168, 1, 0)), 30).unwrap();
+        let all: Vec<Ipv4Addr> = network_info.addresses().collect();
+        assert_eq!(
+            all,
+            vec![
+                Ipv4Addr::new(192, 168, 1, 0),
+                Ipv4Addr::new(192, 168, 1, 1),
+                Ipv4Addr::new(192, 168, 1, 2),
+                Ipv4Addr::new(192, 168, 1, 3),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_hosts_excludes_network_and_broadcast() {
+        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(192, 168, 1, 0)), 30).unwrap();
+        let hosts: Vec<Ipv4Addr> = network_info.hosts().collect();
+        assert_eq!(hosts, vec![Ipv4Addr::new(192, 168, 1, 1), Ipv4Addr::new(192, 168, 1, 2)]);
+    }
+
+    #[test]
+    fn test_hosts_on_point_to_point_link() {
+        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(192, 168, 1, 0)), 31).unwrap();
+        let hosts: Vec<Ipv4Addr> = network_info.hosts().collect();
+        assert_eq!(hosts, vec![Ipv4Addr::new(192, 168, 1, 0), Ipv4Addr::new(192, 168, 1, 1)]);
+    }
+
+    #[test]
+    fn test_hosts_on_single_address() {
+        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(10, 1, 1, 1)), 32).unwrap();
+        let hosts: Vec<Ipv4Addr> = network_info.hosts().collect();
+        assert_eq!(hosts, vec![Ipv4Addr::new(10, 1, 1, 1)]);
+    }
+
+    #[test]
+    fn test_hosts_empty_for_multicast() {
+        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(224, 0, 0, 1)), 4).unwrap();
+        assert_eq!(network_info.hosts().count(), 0);
+    }
+
+    #[test]
+    fn test_hosts_empty_for_degenerate_multicast_slash_32() {
+        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(224, 0, 0, 5)), 32).unwrap();
+        assert_eq!(network_info.usable_hosts, 0);
+        assert_eq!(network_info.hosts().count(), 0);
+    }
+
+    #[test]
+    fn test_scope_loopback() {
+        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 8).unwrap();
+        assert!(network_info.is_loopback());
+        assert!(!network_info.is_global());
+    }
+
+    #[test]
+    fn test_scope_link_local() {
+        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(169, 254, 1, 1)), 16).unwrap();
+        assert!(network_info.is_link_local());
+        assert!(!network_info.is_global());
+    }
+
+    #[test]
+    fn test_scope_shared_space() {
+        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(100, 64, 0, 1)), 10).unwrap();
+        assert!(network_info.is_shared_space());
+        assert!(!network_info.is_global());
+
+        let not_shared = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(100, 128, 0, 1)), 10).unwrap();
+        assert!(!not_shared.is_shared_space());
+    }
+
+    #[test]
+    fn test_needs_nat_covers_special_scopes_beyond_private() {
+        let shared_space = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(100, 64, 0, 1)), 10).unwrap();
+        assert!(shared_space.needs_nat);
+
+        let link_local = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(169, 254, 1, 1)), 16).unwrap();
+        assert!(link_local.needs_nat);
+    }
+
+    #[test]
+    fn test_scope_this_host() {
+        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(0, 0, 0, 1)), 8).unwrap();
+        assert!(network_info.is_this_host());
+        assert!(!network_info.is_global());
+    }
+
+    #[test]
+    fn test_scope_benchmarking() {
+        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(198, 19, 0, 1)), 15).unwrap();
+        assert!(network_info.is_benchmarking());
+        assert!(!network_info.is_global());
+    }
+
+    #[test]
+    fn test_scope_documentation() {
+        for octets in [(192, 0, 2), (198, 51, 100), (203, 0, 113)] {
+            let network_info = NetworkInfo::analyze_network(
+                IpAddr::V4(Ipv4Addr::new(octets.0, octets.1, octets.2, 1)),
+                24,
+            )
+            .unwrap();
+            assert!(network_info.is_documentation());
+            assert!(!network_info.is_global());
+        }
+    }
+
+    #[test]
+    fn test_scope_6to4_relay() {
+        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(192, 88, 99, 1)), 24).unwrap();
+        assert!(network_info.is_6to4_relay());
+        assert!(!network_info.is_global());
+    }
+
+    #[test]
+    fn test_is_global_true_for_public_address() {
+        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(8, 8, 8, 8)), 24).unwrap();
+        assert!(network_info.is_global());
+    }
+
+    #[test]
+    fn test_is_global_false_for_private_address() {
+        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(192, 168, 1, 0)), 24).unwrap();
+        assert!(!network_info.is_global());
+    }
+
+    #[test]
+    fn test_supernet_is_one_bit_shorter() {
+        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(192, 168, 1, 128)), 25).unwrap();
+        let supernet = network_info.supernet().unwrap();
+        assert_eq!(supernet.cidr, 24);
+        assert_eq!(supernet.network_address, Ipv4Addr::new(192, 168, 1, 0));
+    }
+
+    #[test]
+    fn test_supernet_none_at_zero() {
+        let network_info = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)), 0).unwrap();
+        assert_eq!(network_info.supernet(), None);
+    }
+
+    #[test]
+    fn test_aggregate_merges_buddy_pair() {
+        let a = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(192, 168, 0, 0)), 25).unwrap();
+        let b = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(192, 168, 0, 128)), 25).unwrap();
+        let result = aggregate(&[a, b]);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].cidr, 24);
+        assert_eq!(result[0].network_address, Ipv4Addr::new(192, 168, 0, 0));
+    }
+
+    #[test]
+    fn test_aggregate_drops_contained_network() {
+        let parent = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 0)), 8).unwrap();
+        let child = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(10, 1, 0, 0)), 16).unwrap();
+        let result = aggregate(&[parent, child]);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].cidr, 8);
+    }
+
+    #[test]
+    fn test_aggregate_leaves_unmergeable_networks_separate() {
+        let a = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(192, 168, 0, 0)), 24).unwrap();
+        let b = NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(192, 168, 5, 0)), 24).unwrap();
+        let result = aggregate(&[a, b]);
+        assert_eq!(result.len(), 2);
+    }
+
+    #[test]
+    fn test_aggregate_collapses_to_fixed_point() {
+        // Four consecutive /26s should collapse all the way to a single /24.
+        let nets: Vec<NetworkInfo> = (0u8..4)
+            .map(|i| {
+                NetworkInfo::analyze_network(IpAddr::V4(Ipv4Addr::new(192, 168, 1, i * 64)), 26).unwrap()
+            })
+            .collect();
+        let result = aggregate(&nets);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].cidr, 24);
+        assert_eq!(result[0].network_address, Ipv4Addr::new(192, 168, 1, 0));
+    }
+
+    #[test]
+    fn test_default_policy_matches_builtin_behavior() {
+        let policy = AllocationPolicy::default();
+        let network_info = NetworkInfo::analyze_network_with_policy(
+            IpAddr::V4(Ipv4Addr::new(192, 168, 1, 0)),
+            24,
+            &policy,
+        )
+        .unwrap();
+        assert_eq!(network_info.default_gateway, Some(Ipv4Addr::new(192, 168, 1, 1)));
+        assert_eq!(network_info.dhcp_range_start, Some(Ipv4Addr::new(192, 168, 1, 10)));
+        assert_eq!(network_info.dhcp_range_end, Some(Ipv4Addr::new(192, 168, 1, 100)));
+    }
+
+    #[test]
+    fn test_policy_clamps_pool_on_tiny_subnet() {
+        let policy = AllocationPolicy::default();
+        let network_info = NetworkInfo::analyze_network_with_policy(
+            IpAddr::V4(Ipv4Addr::new(192, 168, 1, 0)),
+            30,
+            &policy,
+        )
+        .unwrap();
+        // Only two usable hosts exist; the pool must stay within them and
+        // never collide with the gateway, unlike the old hard-coded offsets.
+        assert_eq!(network_info.default_gateway, Some(Ipv4Addr::new(192, 168, 1, 1)));
+        assert_eq!(network_info.dhcp_range_start, Some(Ipv4Addr::new(192, 168, 1, 2)));
+        assert_eq!(network_info.dhcp_range_end, Some(Ipv4Addr::new(192, 168, 1, 2)));
+    }
+
+    #[test]
+    fn test_policy_gateway_last() {
+        let policy = AllocationPolicy {
+            gateway_position: GatewayPosition::Last,
+            dhcp_pool_start_offset: 0,
+            dhcp_pool_size: 10,
+        };
+        let network_info = NetworkInfo::analyze_network_with_policy(
+            IpAddr::V4(Ipv4Addr::new(192, 168, 1, 0)),
+            24,
+            &policy,
+        )
+        .unwrap();
+        assert_eq!(network_info.default_gateway, Some(Ipv4Addr::new(192, 168, 1, 254)));
+        assert_eq!(network_info.dhcp_range_start, Some(Ipv4Addr::new(192, 168, 1, 1)));
+        assert_eq!(network_info.dhcp_range_end, Some(Ipv4Addr::new(192, 168, 1, 10)));
+    }
+
+    #[test]
+    fn test_policy_gateway_last_on_tiny_subnet_avoids_collision() {
+        // Only two usable hosts exist (192.168.1.1 and .2); the gateway
+        // takes the last one, so the pool must fall back to the other
+        // instead of colliding with it.
+        let policy = AllocationPolicy::default();
+        let network_info = NetworkInfo::analyze_network_with_policy(
+            IpAddr::V4(Ipv4Addr::new(192, 168, 1, 0)),
+            30,
+            &AllocationPolicy {
+                gateway_position: GatewayPosition::Last,
+                ..policy
+            },
+        )
+        .unwrap();
+        assert_eq!(network_info.default_gateway, Some(Ipv4Addr::new(192, 168, 1, 2)));
+        assert_eq!(network_info.dhcp_range_start, Some(Ipv4Addr::new(192, 168, 1, 1)));
+        assert_eq!(network_info.dhcp_range_end, Some(Ipv4Addr::new(192, 168, 1, 1)));
+        assert_ne!(network_info.dhcp_range_start, network_info.default_gateway);
+        assert_ne!(network_info.dhcp_range_end, network_info.default_gateway);
+    }
+
+    #[test]
+    fn test_policy_has_no_effect_without_host_range() {
+        let policy = AllocationPolicy::default();
+        let network_info = NetworkInfo::analyze_network_with_policy(
+            IpAddr::V4(Ipv4Addr::new(224, 0, 0, 1)),
+            4,
+            &policy,
+        )
+        .unwrap();
+        assert_eq!(network_info.default_gateway, None);
+        assert_eq!(network_info.dhcp_range_start, None);
+        assert_eq!(network_info.dhcp_range_end, None);
+    }
 }